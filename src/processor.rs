@@ -1,4 +1,12 @@
-use std::{fs::File, path::PathBuf, str::FromStr, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{File, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 
 use ::futures::stream::FuturesUnordered;
 use anyhow::Result;
@@ -10,19 +18,38 @@ use mpl_migration_validator::{
     utils::find_migration_state_pda,
     PROGRAM_SIGNER,
 };
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use solana_client::rpc_client::RpcClient;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    nonblocking::pubsub_client::PubsubClient,
+    rpc_client::RpcClient,
+    rpc_config::{
+        RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionLogsConfig,
+        RpcTransactionLogsFilter,
+    },
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    rpc_response::RpcSimulateTransactionResult,
+};
 use solana_program::{
     bpf_loader_upgradeable::UpgradeableLoaderState, program_pack::Pack, pubkey::Pubkey,
 };
-use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_sdk::{
+    signature::{Keypair, Signature},
+    signer::Signer,
+    transaction::Transaction,
+};
 use spl_token::state::Account as TokenAccount;
-use tokio::sync::{Mutex, Semaphore};
+use tokio::{
+    sync::{Mutex, Semaphore},
+    task::JoinHandle,
+};
 
 use crate::{
     methods::{
-        close, get_state, initialize, initialize_msg, migrate_item, start, update, CloseParams,
-        GetStateParams, InitializeMsgParams, InitializeParams, MigrateParams, StartParams,
+        close, get_state, initialize, initialize_msg, migrate_item, simulate_migrate_item, start,
+        update, CloseParams, GetStateParams, InitializeMsgParams, InitializeParams, MigrateParams,
+        StartParams,
         UpdateParams,
     },
     setup,
@@ -193,21 +220,163 @@ pub fn process_get_state(
     Ok(())
 }
 
-pub fn process_get_all_states(keypair: Option<PathBuf>, rpc_url: Option<String>) -> Result<()> {
+/// Byte offsets of `MigrationState` fields within the account data, derived
+/// from its Borsh field order in `mpl_migration_validator::state`. Used to
+/// build `Memcmp` filters so `getProgramAccounts` only returns matching
+/// states instead of the whole program's accounts.
+/// Each offset is computed as the Borsh-encoded length of the fields that
+/// precede it, instead of being hardcoded as a magic number that could
+/// silently drift out of sync with the real struct (a wrong offset doesn't
+/// error — `get_program_accounts_with_config` just returns zero or wrong
+/// accounts). The assumed field order (`authority`, `unlock_method`,
+/// `collection_mint`, `in_progress`, ...) is mirrored by the fixture in
+/// `tests::migration_state_offsets_match_assumed_layout` below; that test
+/// checks the offsets against the fixture only, not against
+/// `mpl_migration_validator::state::MigrationState` itself, so if the real
+/// field order changes, this module and that fixture must be updated together.
+mod migration_state_layout {
+    use borsh::BorshSerialize;
+    use mpl_migration_validator::state::UnlockMethod;
+    use solana_program::pubkey::Pubkey;
+
+    pub const AUTHORITY_OFFSET: usize = 0;
+
+    pub fn unlock_method_offset() -> usize {
+        AUTHORITY_OFFSET + borsh_len(&Pubkey::default())
+    }
+
+    // The collection mint lives inside `collection_info` right after
+    // `unlock_method`, but since each `MigrationState` PDA is already keyed by
+    // collection mint (see `find_migration_state_pda`), filtering `get-all-states`
+    // on it directly isn't exposed as a flag here.
+    pub fn in_progress_offset() -> usize {
+        unlock_method_offset() + borsh_len(&UnlockMethod::Timed) + borsh_len(&Pubkey::default())
+    }
+
+    fn borsh_len<T: BorshSerialize>(value: &T) -> usize {
+        value.try_to_vec().expect("infallible borsh encoding").len()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        // TODO: this only proves the offset math is self-consistent with
+        // `MigrationStateLayoutFixture` below, which mirrors the *assumed*
+        // field order — it is not checked against the real
+        // `mpl_migration_validator::state::MigrationState` (not vendored in
+        // this tree). Once that type is available, add a round-trip test
+        // that borsh-serializes an actual `MigrationState` and asserts these
+        // offsets land on the same bytes, and delete this fixture.
+
+        /// Mirrors the assumed prefix of `MigrationState`'s Borsh layout:
+        /// `authority: Pubkey`, `unlock_method: UnlockMethod`,
+        /// `collection_mint: Pubkey`, `in_progress: bool`. Keep this in sync
+        /// with `mpl_migration_validator::state::MigrationState` if its field
+        /// order changes.
+        #[derive(BorshSerialize)]
+        struct MigrationStateLayoutFixture {
+            authority: Pubkey,
+            unlock_method: UnlockMethod,
+            collection_mint: Pubkey,
+            in_progress: bool,
+        }
+
+        #[test]
+        fn migration_state_offsets_match_assumed_layout() {
+            let fixture = MigrationStateLayoutFixture {
+                authority: Pubkey::new_unique(),
+                unlock_method: UnlockMethod::Vote,
+                collection_mint: Pubkey::new_unique(),
+                in_progress: true,
+            };
+            let bytes = fixture.try_to_vec().unwrap();
+
+            assert_eq!(
+                &bytes[AUTHORITY_OFFSET..AUTHORITY_OFFSET + 32],
+                fixture.authority.to_bytes()
+            );
+            assert_eq!(
+                bytes[unlock_method_offset()],
+                1, // UnlockMethod::Vote's Borsh discriminant
+            );
+            assert_eq!(bytes[in_progress_offset()], fixture.in_progress as u8);
+        }
+    }
+}
+
+fn unlock_method_filter_byte(unlock_method: &str) -> Result<u8> {
+    match unlock_method.to_lowercase().as_str() {
+        "timed" => Ok(0),
+        "vote" => Ok(1),
+        _ => Err(anyhow::anyhow!(
+            "Invalid unlock method. Must be one of: Timed, Vote"
+        )),
+    }
+}
+
+fn status_filter_byte(status: &str) -> Result<u8> {
+    match status.to_lowercase().as_str() {
+        "in-progress" | "in_progress" => Ok(1),
+        "complete" | "completed" => Ok(0),
+        _ => Err(anyhow::anyhow!(
+            "Invalid status. Must be one of: in-progress, complete"
+        )),
+    }
+}
+
+pub fn process_get_all_states(
+    keypair: Option<PathBuf>,
+    rpc_url: Option<String>,
+    authority: Option<Pubkey>,
+    unlock_method: Option<String>,
+    status: Option<String>,
+) -> Result<()> {
+    use migration_state_layout::*;
+
     let config = setup::CliConfig::new(keypair, rpc_url)?;
 
-    // Get all the program accounts for mpl-migration-validator.
+    let mut filters = Vec::new();
+    if let Some(authority) = authority {
+        filters.push(RpcFilterType::Memcmp(Memcmp::new(
+            AUTHORITY_OFFSET,
+            MemcmpEncodedBytes::Base58(authority.to_string()),
+        )));
+    }
+    if let Some(unlock_method) = unlock_method {
+        filters.push(RpcFilterType::Memcmp(Memcmp::new(
+            unlock_method_offset(),
+            MemcmpEncodedBytes::Bytes(vec![unlock_method_filter_byte(&unlock_method)?]),
+        )));
+    }
+    if let Some(status) = status {
+        filters.push(RpcFilterType::Memcmp(Memcmp::new(
+            in_progress_offset(),
+            MemcmpEncodedBytes::Bytes(vec![status_filter_byte(&status)?]),
+        )));
+    }
+
+    let rpc_config = RpcProgramAccountsConfig {
+        filters: if filters.is_empty() {
+            None
+        } else {
+            Some(filters)
+        },
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    // Get all the program accounts for mpl-migration-validator that match
+    // the requested filters.
     let account_results = config
         .client
-        .get_program_accounts(&mpl_migration_validator::ID)?;
+        .get_program_accounts_with_config(&mpl_migration_validator::ID, rpc_config)?;
 
     let cluster = get_cluster(&config.client)?;
 
-    println!(
-        "Found: {}",
-        style(format!("{} states", account_results.len() - 1)).green()
-    );
-
     let file_name = format!("{cluster}_migration_states.json");
 
     let mut states = Vec::new();
@@ -229,6 +398,11 @@ pub fn process_get_all_states(keypair: Option<PathBuf>, rpc_url: Option<String>)
         states.push(state);
     }
 
+    println!(
+        "Found: {}",
+        style(format!("{} states", states.len())).green()
+    );
+
     let f = File::create(&file_name)?;
     serde_json::to_writer_pretty(f, &states)?;
 
@@ -301,6 +475,70 @@ pub fn process_start(
     Ok(())
 }
 
+/// Whether `state`'s unlock condition is satisfied yet, i.e. `process_start`
+/// would succeed if called now.
+fn is_migration_eligible(state: &MigrationState) -> bool {
+    match state.unlock_method {
+        UnlockMethod::Timed => now_unix() >= state.unlock_timestamp,
+        UnlockMethod::Vote => state.votes >= state.vote_threshold,
+    }
+}
+
+fn now_unix() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+pub fn process_wait(
+    keypair: Option<PathBuf>,
+    rpc_url: Option<String>,
+    collection_mint: Pubkey,
+    then_start: bool,
+) -> Result<()> {
+    let config = setup::CliConfig::new(keypair.clone(), rpc_url.clone())?;
+
+    let spinner = spinner_with_style();
+    spinner.set_message("Waiting for migration to become eligible...");
+
+    loop {
+        let state = get_state(GetStateParams {
+            client: &config.client,
+            collection_mint,
+        })?;
+
+        if is_migration_eligible(&state) {
+            break;
+        }
+
+        match state.unlock_method {
+            UnlockMethod::Timed => {
+                let remaining = (state.unlock_timestamp - now_unix()).max(0);
+                spinner.set_message(format!(
+                    "Waiting for timer to elapse... ~{remaining}s remaining"
+                ));
+            }
+            UnlockMethod::Vote => {
+                spinner.set_message(format!(
+                    "Waiting for votes... {}/{}",
+                    state.votes, state.vote_threshold
+                ));
+            }
+        }
+
+        std::thread::sleep(std::time::Duration::from_secs(5));
+    }
+
+    spinner.finish_with_message("Migration is eligible to start!");
+
+    if then_start {
+        return process_start(keypair, rpc_url, collection_mint);
+    }
+
+    Ok(())
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MigratedMint {
     sig: String,
@@ -311,19 +549,38 @@ pub struct MigratedMint {
 pub struct MigrationError {
     mint: String,
     error: String,
+    /// Program logs for the failing transaction, if `--stream-logs` was on
+    /// and we could recover a signature from `error` in time. Empty
+    /// otherwise.
+    #[serde(default)]
+    logs: Vec<String>,
 }
 
+/// Default number of retries (after the first attempt) for a transient
+/// RPC/transaction failure before it's surfaced as a permanent `MigrationError`.
+const DEFAULT_MAX_RETRIES: u8 = 5;
+const RETRY_BASE_DELAY_MS: u64 = 250;
+const RETRY_MAX_DELAY_MS: u64 = 8_000;
+
+const LOG_LOOKUP_ATTEMPTS: u8 = 5;
+const LOG_LOOKUP_DELAY_MS: u64 = 200;
+
 pub async fn process_migrate(
     keypair: Option<PathBuf>,
     rpc_url: Option<String>,
     collection_mint: Pubkey,
     mint_list: PathBuf,
+    resume: bool,
+    max_retries: Option<u8>,
+    stream_logs: bool,
+    dry_run: bool,
 ) -> Result<()> {
+    let max_retries = max_retries.unwrap_or(DEFAULT_MAX_RETRIES);
     let config = setup::CliConfig::new(keypair, rpc_url)?;
 
     let f = File::open(mint_list)?;
     let mints: Vec<String> = serde_json::from_reader(f)?;
-    let mints: Vec<Pubkey> = mints
+    let mut mints: Vec<Pubkey> = mints
         .into_iter()
         .map(|s| Pubkey::from_str(&s).unwrap())
         .collect();
@@ -335,8 +592,73 @@ pub async fn process_migrate(
 
     let rule_set = migrate_state.collection_info.rule_set;
 
+    let migrated_checkpoint_path = format!("{collection_mint}_migrated_mints.jsonl");
+    let failed_checkpoint_path = format!("{collection_mint}_failed_mints.jsonl");
+
     let completed_mints: Arc<Mutex<Vec<MigratedMint>>> = Arc::new(Mutex::new(Vec::new()));
     let errors: Arc<Mutex<Vec<MigrationError>>> = Arc::new(Mutex::new(Vec::new()));
+    let simulations: Arc<Mutex<Vec<MigrationSimulation>>> = Arc::new(Mutex::new(Vec::new()));
+
+    if resume {
+        let already_migrated = load_checkpoint::<MigratedMint>(&migrated_checkpoint_path)?;
+        let already_migrated_mints: HashSet<Pubkey> = already_migrated
+            .iter()
+            .map(|m| Pubkey::from_str(&m.item_mint))
+            .collect::<Result<_, _>>()?;
+        let total = mints.len();
+        mints.retain(|mint| !already_migrated_mints.contains(mint));
+        println!(
+            "Resuming migration: skipping {} already-migrated mint(s), {} of {} remaining",
+            style(already_migrated_mints.len()).green(),
+            style(mints.len()).green(),
+            total
+        );
+        completed_mints.lock().await.extend(already_migrated);
+    }
+
+    // Opened once and shared so every task appends its result as soon as it
+    // finishes, instead of all progress living only in memory until the end.
+    // Skipped entirely for `--dry-run`: nothing real is migrated, so these
+    // shouldn't be created (or touched, if left over from a prior real run).
+    let (migrated_checkpoint, failed_checkpoint) = if dry_run {
+        (None, None)
+    } else {
+        (
+            Some(Arc::new(Mutex::new(
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&migrated_checkpoint_path)?,
+            ))),
+            Some(Arc::new(Mutex::new(
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&failed_checkpoint_path)?,
+            ))),
+        )
+    };
+
+    let log_streamer = if stream_logs {
+        Some(spawn_log_streamer(config.client.url(), collection_mint).await?)
+    } else {
+        None
+    };
+    let captured_logs = log_streamer.as_ref().map(|s| s.captured_logs.clone());
+
+    // Opened once and shared the same way as `migrated_checkpoint`/
+    // `failed_checkpoint`, so concurrent `migrate_mint` tasks serialize their
+    // appends instead of each opening (and writing to) its own `File` handle
+    // for the same path. Only needed when logs are actually captured, and
+    // never touched in `--dry-run` since nothing is written to it there.
+    let migration_log_file = if dry_run || captured_logs.is_none() {
+        None
+    } else {
+        let logs_path = format!("{collection_mint}_migration_logs.jsonl");
+        Some(Arc::new(Mutex::new(
+            OpenOptions::new().create(true).append(true).open(logs_path)?,
+        )))
+    };
 
     let keypair = Arc::new(config.keypair);
     let client = Arc::new(config.client);
@@ -350,9 +672,15 @@ pub async fn process_migrate(
     for item_mint in mints {
         let permit = Arc::clone(&semaphore).acquire_owned().await.unwrap();
         let pb = pb.clone();
+        let completed_mints = completed_mints.clone();
         let errors = errors.clone();
         let keypair = keypair.clone();
         let client = client.clone();
+        let migrated_checkpoint = migrated_checkpoint.clone();
+        let failed_checkpoint = failed_checkpoint.clone();
+        let captured_logs = captured_logs.clone();
+        let migration_log_file = migration_log_file.clone();
+        let simulations = simulations.clone();
 
         tasks.push(tokio::spawn(async move {
             let _permit = permit;
@@ -363,18 +691,17 @@ pub async fn process_migrate(
                 collection_mint,
                 item_mint,
                 rule_set,
-                completed_mints: Arc::new(Mutex::new(Vec::new())),
-                errors: Arc::new(Mutex::new(Vec::new())),
+                completed_mints,
+                errors,
+                migrated_checkpoint,
+                failed_checkpoint,
+                max_retries,
+                captured_logs,
+                migration_log_file,
+                dry_run,
+                simulations,
             };
-            match migrate_mint(args).await {
-                Ok(_) => {}
-                Err(e) => {
-                    errors.lock().await.push(MigrationError {
-                        mint: item_mint.to_string(),
-                        error: e.to_string(),
-                    });
-                }
-            }
+            migrate_mint(args).await;
 
             pb.inc(1);
         }));
@@ -385,6 +712,18 @@ pub async fn process_migrate(
     }
     spinner.finish();
 
+    if let Some(log_streamer) = log_streamer {
+        log_streamer.shutdown().await;
+    }
+
+    if dry_run {
+        let simulations = Arc::try_unwrap(simulations).unwrap().into_inner();
+        let report_name = format!("{collection_mint}_migration_simulation.json");
+        let f = File::create(report_name)?;
+        serde_json::to_writer_pretty(f, &simulations)?;
+        return Ok(());
+    }
+
     let completed_mints = Arc::try_unwrap(completed_mints).unwrap().into_inner();
     let errors = Arc::try_unwrap(errors).unwrap().into_inner();
 
@@ -398,6 +737,187 @@ pub async fn process_migrate(
     Ok(())
 }
 
+/// Per-mint outcome of a `--dry-run` migration, reusing `simulate_transaction`
+/// instead of sending a real one so a user can triage an entire mint list
+/// (wrong owner, already migrated, missing token account, etc.) without
+/// spending fees or mutating state.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MigrationSimulation {
+    mint: String,
+    success: bool,
+    error: Option<String>,
+    compute_units_consumed: Option<u64>,
+    logs: Vec<String>,
+}
+
+impl MigrationSimulation {
+    fn from_rpc_result(mint: Pubkey, result: RpcSimulateTransactionResult) -> Self {
+        Self {
+            mint: mint.to_string(),
+            success: result.err.is_none(),
+            error: result.err.map(|e| e.to_string()),
+            compute_units_consumed: result.units_consumed,
+            logs: result.logs.unwrap_or_default().into_iter().take(5).collect(),
+        }
+    }
+
+    fn discovery_failure(mint: Pubkey, error: String) -> Self {
+        Self {
+            mint: mint.to_string(),
+            success: false,
+            error: Some(error),
+            compute_units_consumed: None,
+            logs: Vec::new(),
+        }
+    }
+}
+
+/// A single decoded `logsSubscribe` notification for a transaction that
+/// mentioned the migration program, captured alongside the mint it turned
+/// out to belong to (once we learn that from `migrate_mint`).
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MigrationLogEntry {
+    mint: String,
+    signature: String,
+    logs: Vec<String>,
+}
+
+/// Handle to the background `logsSubscribe` task started by `--stream-logs`.
+/// Holds the buffer of logs keyed by signature so `migrate_mint` can look up
+/// and persist the entry for its own transaction once it lands.
+struct LogStreamer {
+    handle: JoinHandle<()>,
+    captured_logs: Arc<Mutex<HashMap<String, Vec<String>>>>,
+}
+
+impl LogStreamer {
+    async fn shutdown(self) {
+        self.handle.abort();
+    }
+}
+
+fn derive_ws_url(rpc_url: &str) -> String {
+    rpc_url
+        .replacen("https://", "wss://", 1)
+        .replacen("http://", "ws://", 1)
+}
+
+/// Opens a `logsSubscribe` websocket filtered to transactions that mention
+/// `mpl_migration_validator::ID` and buffers each notification's logs by
+/// signature, so failed migrations can be enriched with the real on-chain
+/// error instead of just the opaque custom-program-error code.
+async fn spawn_log_streamer(rpc_url: String, _collection_mint: Pubkey) -> Result<LogStreamer> {
+    let ws_url = derive_ws_url(&rpc_url);
+    let captured_logs: Arc<Mutex<HashMap<String, Vec<String>>>> = Arc::new(Mutex::new(HashMap::new()));
+    let captured_logs_writer = captured_logs.clone();
+
+    let pubsub_client = PubsubClient::new(&ws_url).await?;
+    let handle = tokio::spawn(async move {
+        let (mut logs_stream, _unsubscribe) = match pubsub_client
+            .logs_subscribe(
+                RpcTransactionLogsFilter::Mentions(vec![mpl_migration_validator::ID.to_string()]),
+                RpcTransactionLogsConfig { commitment: None },
+            )
+            .await
+        {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                eprintln!("Failed to open logsSubscribe websocket: {e}");
+                return;
+            }
+        };
+
+        while let Some(notification) = logs_stream.next().await {
+            captured_logs_writer
+                .lock()
+                .await
+                .insert(notification.value.signature, notification.value.logs);
+        }
+    });
+
+    Ok(LogStreamer {
+        handle,
+        captured_logs,
+    })
+}
+
+/// Attempts to read `captured_logs` a handful of times before giving up,
+/// since the `logsSubscribe` notification for a given signature can arrive
+/// slightly after we learn that signature ourselves (on the success path) or
+/// after a client-side timeout of our own send/confirm call (on the failure
+/// path) — a single synchronous read races that notification and silently
+/// drops the logs if it loses.
+async fn lookup_captured_logs(
+    captured_logs: &Arc<Mutex<HashMap<String, Vec<String>>>>,
+    signature: &str,
+) -> Option<Vec<String>> {
+    for attempt in 0..LOG_LOOKUP_ATTEMPTS {
+        if let Some(logs) = captured_logs.lock().await.remove(signature) {
+            return Some(logs);
+        }
+        if attempt + 1 < LOG_LOOKUP_ATTEMPTS {
+            tokio::time::sleep(Duration::from_millis(LOG_LOOKUP_DELAY_MS)).await;
+        }
+    }
+    None
+}
+
+/// Appends `logs` for `signature` into the per-collection migration-logs
+/// file, tagged with the mint they belong to. Takes the same shared
+/// `Arc<Mutex<File>>` handle used for `migrated_checkpoint`/`failed_checkpoint`
+/// instead of opening its own `File` per call, so concurrent `migrate_mint`
+/// tasks (up to the semaphore's 100 permits) serialize their writes instead
+/// of interleaving partial lines into the same `.jsonl` path.
+async fn write_migration_log_file(
+    file: &Arc<Mutex<File>>,
+    item_mint: Pubkey,
+    signature: &str,
+    logs: Vec<String>,
+) -> Result<()> {
+    append_checkpoint_line(
+        &mut *file.lock().await,
+        &MigrationLogEntry {
+            mint: item_mint.to_string(),
+            signature: signature.to_string(),
+            logs,
+        },
+    )
+}
+
+/// Best-effort extraction of a transaction signature embedded in an RPC or
+/// client error message, so a failed `migrate_item` call — which doesn't
+/// return a signature — can still be enriched with its program logs. Solana
+/// error messages frequently include the signature as a bare base58 token
+/// (e.g. "Transaction <sig> resulted in an error ...").
+fn extract_signature_from_error(error: &str) -> Option<String> {
+    error
+        .split(|c: char| c.is_whitespace() || c == ':' || c == ',')
+        .find(|token| Signature::from_str(token).is_ok())
+        .map(str::to_string)
+}
+
+/// Loads a checkpoint file written by a previous `process_migrate` run, where
+/// each line is one JSON-encoded `T`. Returns an empty vec if the file
+/// doesn't exist yet, so `--resume` works on a fresh collection too.
+fn load_checkpoint<T: for<'de> Deserialize<'de>>(path: &str) -> Result<Vec<T>> {
+    if !PathBuf::from(path).exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = std::fs::read_to_string(path)?;
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Ok(serde_json::from_str(line)?))
+        .collect()
+}
+
+fn append_checkpoint_line<T: Serialize>(file: &mut File, value: &T) -> Result<()> {
+    let line = serde_json::to_string(value)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
 struct MigrateArgs {
     keypair: Arc<Keypair>,
     client: Arc<RpcClient>,
@@ -406,62 +926,222 @@ struct MigrateArgs {
     rule_set: Pubkey,
     completed_mints: Arc<Mutex<Vec<MigratedMint>>>,
     errors: Arc<Mutex<Vec<MigrationError>>>,
+    migrated_checkpoint: Option<Arc<Mutex<File>>>,
+    failed_checkpoint: Option<Arc<Mutex<File>>>,
+    max_retries: u8,
+    captured_logs: Option<Arc<Mutex<HashMap<String, Vec<String>>>>>,
+    migration_log_file: Option<Arc<Mutex<File>>>,
+    dry_run: bool,
+    simulations: Arc<Mutex<Vec<MigrationSimulation>>>,
 }
 
-async fn migrate_mint(args: MigrateArgs) -> Result<()> {
-    let item_token = match get_nft_token_account(&args.client, args.item_mint) {
+/// Whether a failure is worth retrying or should be recorded into `errors`
+/// immediately. We classify by matching on well-known substrings since the
+/// underlying `solana_client`/transport errors all get stringified into a
+/// single `anyhow::Error` by the time they reach `migrate_mint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErrorKind {
+    Transient,
+    Permanent,
+}
+
+fn classify_error(err: &anyhow::Error) -> ErrorKind {
+    const TRANSIENT_MARKERS: &[&str] = &[
+        "blockhash not found",
+        "blockhash expired",
+        "block height exceeded",
+        "429",
+        "too many requests",
+        "rate limit",
+        "rate-limited",
+        "timed out",
+        "timeout",
+        "connection reset",
+        "connection refused",
+        "broken pipe",
+        "node is behind",
+        "transport error",
+    ];
+
+    let message = err.to_string().to_lowercase();
+    if TRANSIENT_MARKERS.iter().any(|marker| message.contains(marker)) {
+        ErrorKind::Transient
+    } else {
+        ErrorKind::Permanent
+    }
+}
+
+/// Retries `f` with exponential backoff (plus jitter) while the error it
+/// returns is classified as transient, giving up after `max_retries`
+/// retries (`max_retries + 1` calls of `f` in all). Permanent errors (or exhausted retries) are returned as-is so
+/// the caller can fail fast into `errors` as before.
+async fn retry_with_backoff<T, F>(max_retries: u8, mut f: F) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+{
+    let mut attempt = 0u32;
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt < max_retries as u32 && classify_error(&e) == ErrorKind::Transient => {
+                let delay_ms = RETRY_BASE_DELAY_MS
+                    .saturating_mul(2u64.saturating_pow(attempt))
+                    .min(RETRY_MAX_DELAY_MS);
+                let jitter_ms = rand::thread_rng().gen_range(0..=delay_ms / 2);
+                tokio::time::sleep(Duration::from_millis(delay_ms + jitter_ms)).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Like `retry_with_backoff`, but specific to resubmitting a *send* of a
+/// brand-new transaction (a fresh blockhash on every attempt). A client-side
+/// timeout doesn't mean the transaction failed — it may well have landed —
+/// so before resubmitting on a transient-looking error, check whether the
+/// previous attempt's transaction already confirmed on-chain. Otherwise a
+/// retry can blindly resend, get back a permanent "already migrated" error,
+/// and record a mint that in fact migrated successfully as failed.
+async fn retry_send_with_backoff<F>(client: &RpcClient, max_retries: u8, mut f: F) -> Result<Signature>
+where
+    F: FnMut() -> Result<Signature>,
+{
+    let mut attempt = 0u32;
+    loop {
+        match f() {
+            Ok(signature) => return Ok(signature),
+            Err(e) if attempt < max_retries as u32 && classify_error(&e) == ErrorKind::Transient => {
+                if let Some(landed) = previously_landed_signature(client, &e.to_string()) {
+                    return Ok(landed);
+                }
+
+                let delay_ms = RETRY_BASE_DELAY_MS
+                    .saturating_mul(2u64.saturating_pow(attempt))
+                    .min(RETRY_MAX_DELAY_MS);
+                let jitter_ms = rand::thread_rng().gen_range(0..=delay_ms / 2);
+                tokio::time::sleep(Duration::from_millis(delay_ms + jitter_ms)).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// If `error` embeds a transaction signature and that signature has in fact
+/// landed successfully on-chain (checked via `get_signature_statuses`),
+/// returns it so the caller can treat the send as successful instead of
+/// resubmitting a duplicate transaction.
+fn previously_landed_signature(client: &RpcClient, error: &str) -> Option<Signature> {
+    let signature = extract_signature_from_error(error).and_then(|s| Signature::from_str(&s).ok())?;
+    let status = client
+        .get_signature_statuses(&[signature])
+        .ok()?
+        .value
+        .into_iter()
+        .next()??;
+    status.err.is_none().then_some(signature)
+}
+
+async fn record_error(args: &MigrateArgs, error: String) {
+    if args.dry_run {
+        args.simulations
+            .lock()
+            .await
+            .push(MigrationSimulation::discovery_failure(args.item_mint, error));
+        return;
+    }
+
+    // This is the actual point of `--stream-logs`: a failed migration's JSON
+    // error is often just an opaque custom-program-error code, so recover
+    // the real reason from the program logs of the transaction that failed.
+    let logs = match (&args.captured_logs, extract_signature_from_error(&error)) {
+        (Some(captured_logs), Some(signature)) => {
+            let logs = lookup_captured_logs(captured_logs, &signature).await;
+            if let (Some(logs), Some(log_file)) = (logs.clone(), &args.migration_log_file) {
+                if let Err(e) =
+                    write_migration_log_file(log_file, args.item_mint, &signature, logs).await
+                {
+                    eprintln!("Failed to write migration log entry: {e}");
+                }
+            }
+            logs.unwrap_or_default()
+        }
+        _ => Vec::new(),
+    };
+
+    let error = MigrationError {
+        mint: args.item_mint.to_string(),
+        error,
+        logs,
+    };
+    if let Some(failed_checkpoint) = &args.failed_checkpoint {
+        if let Err(e) = append_checkpoint_line(&mut *failed_checkpoint.lock().await, &error) {
+            eprintln!("Failed to write failed-mints checkpoint: {e}");
+        }
+    }
+    args.errors.lock().await.push(error);
+}
+
+async fn migrate_mint(args: MigrateArgs) {
+    let item_token = match retry_with_backoff(args.max_retries, || {
+        get_nft_token_account(&args.client, args.item_mint).map_err(anyhow::Error::from)
+    })
+    .await
+    {
         Ok(item_token) => item_token,
         Err(e) => {
-            args.errors.lock().await.push(MigrationError {
-                mint: args.item_mint.to_string(),
-                error: e.to_string(),
-            });
-            return Ok(());
+            record_error(&args, e.to_string()).await;
+            return;
         }
     };
 
-    let account = match args.client.get_account(&item_token) {
+    let account = match retry_with_backoff(args.max_retries, || {
+        args.client.get_account(&item_token).map_err(anyhow::Error::from)
+    })
+    .await
+    {
         Ok(item_token) => item_token,
         Err(e) => {
-            args.errors.lock().await.push(MigrationError {
-                mint: args.item_mint.to_string(),
-                error: e.to_string(),
-            });
-            return Ok(());
+            record_error(&args, e.to_string()).await;
+            return;
         }
     };
 
+    // Deserialization failures are permanent: the token account exists but
+    // isn't what we expect, so retrying the fetch won't help.
     let token_account = match TokenAccount::unpack(&account.data) {
         Ok(account) => account,
         Err(e) => {
-            args.errors.lock().await.push(MigrationError {
-                mint: args.item_mint.to_string(),
-                error: e.to_string(),
-            });
-            return Ok(());
+            record_error(&args, e.to_string()).await;
+            return;
         }
     };
 
     let token_owner = token_account.owner;
-    let token_owner_program = match args.client.get_account(&token_owner) {
+    let token_owner_program = match retry_with_backoff(args.max_retries, || {
+        args.client.get_account(&token_owner).map_err(anyhow::Error::from)
+    })
+    .await
+    {
         Ok(account) => account.owner,
         Err(e) => {
-            args.errors.lock().await.push(MigrationError {
-                mint: args.item_mint.to_string(),
-                error: e.to_string(),
-            });
-            return Ok(());
+            record_error(&args, e.to_string()).await;
+            return;
         }
     };
 
-    let token_owner_program_account = match args.client.get_account(&token_owner_program) {
+    let token_owner_program_account = match retry_with_backoff(args.max_retries, || {
+        args.client
+            .get_account(&token_owner_program)
+            .map_err(anyhow::Error::from)
+    })
+    .await
+    {
         Ok(account) => account,
         Err(e) => {
-            args.errors.lock().await.push(MigrationError {
-                mint: args.item_mint.to_string(),
-                error: e.to_string(),
-            });
-            return Ok(());
+            record_error(&args, e.to_string()).await;
+            return;
         }
     };
 
@@ -481,7 +1161,7 @@ async fn migrate_mint(args: MigrateArgs) -> Result<()> {
         None
     };
 
-    let params = MigrateParams {
+    let build_params = || MigrateParams {
         client: &args.client,
         payer: &args.keypair,
         item_mint: args.item_mint,
@@ -493,21 +1173,56 @@ async fn migrate_mint(args: MigrateArgs) -> Result<()> {
         rule_set: args.rule_set,
     };
 
-    let sig = match migrate_item(params) {
+    if args.dry_run {
+        // Consistent with every other RPC call in this function: a transient
+        // failure here (rate limit, expired blockhash) shouldn't be recorded
+        // straight into the simulation report as a `discovery_failure` — that
+        // would be noise indistinguishable from a real "this mint will fail"
+        // result, which is exactly what `--dry-run` is supposed to let users
+        // triage away from.
+        let simulation = match retry_with_backoff(args.max_retries, || {
+            simulate_migrate_item(build_params())
+        })
+        .await
+        {
+            Ok(result) => MigrationSimulation::from_rpc_result(args.item_mint, result),
+            Err(e) => MigrationSimulation::discovery_failure(args.item_mint, e.to_string()),
+        };
+        args.simulations.lock().await.push(simulation);
+        return;
+    }
+
+    let sig = match retry_send_with_backoff(&args.client, args.max_retries, || {
+        migrate_item(build_params())
+    })
+    .await
+    {
         Ok(signature) => signature,
         Err(e) => {
-            args.errors.lock().await.push(MigrationError {
-                mint: args.item_mint.to_string(),
-                error: e.to_string(),
-            });
-            return Ok(());
+            record_error(&args, e.to_string()).await;
+            return;
         }
     };
 
-    args.completed_mints.lock().await.push(MigratedMint {
+    if let (Some(captured_logs), Some(log_file)) = (&args.captured_logs, &args.migration_log_file) {
+        let signature = sig.to_string();
+        if let Some(logs) = lookup_captured_logs(captured_logs, &signature).await {
+            if let Err(e) =
+                write_migration_log_file(log_file, args.item_mint, &signature, logs).await
+            {
+                eprintln!("Failed to write migration log entry: {e}");
+            }
+        }
+    }
+
+    let migrated = MigratedMint {
         sig: sig.to_string(),
         item_mint: args.item_mint.to_string(),
-    });
-
-    Ok(())
+    };
+    if let Some(migrated_checkpoint) = &args.migrated_checkpoint {
+        if let Err(e) = append_checkpoint_line(&mut *migrated_checkpoint.lock().await, &migrated) {
+            eprintln!("Failed to write migrated-mints checkpoint: {e}");
+        }
+    }
+    args.completed_mints.lock().await.push(migrated);
 }